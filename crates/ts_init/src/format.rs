@@ -0,0 +1,165 @@
+//! Selectable event formats shared by the stderr and file layers.
+
+use std::fmt as std_fmt;
+
+use tracing_core::Subscriber;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{self, FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Selects the wire format used when rendering log events.
+///
+/// `Full` matches the crate's existing default (colorized, human-readable).
+/// `Json` and `Logfmt` are meant for feeding log aggregators that expect
+/// machine-parseable output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Single-line, colorized human output (the existing default).
+    #[default]
+    Full,
+    /// Single-line human output with condensed span context.
+    Compact,
+    /// Multi-line human output with indented span context.
+    Pretty,
+    /// One JSON object per event, via `tracing-subscriber`'s JSON formatter.
+    Json(JsonFormat),
+    /// `key=value` pairs, space-separated, quoting values that contain spaces.
+    Logfmt,
+}
+
+/// Configures the shape of [`LogFormat::Json`] output.
+///
+/// The defaults match what a log aggregator typically wants: event fields
+/// flattened to the top level rather than nested under `fields`, plus the
+/// current span list and the event's target included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JsonFormat {
+    /// Flatten event fields to the top level of the JSON object instead of
+    /// nesting them under a `fields` key.
+    pub flatten_event: bool,
+    /// Include the list of the event's parent spans.
+    pub include_span_list: bool,
+    /// Include the event's `target`.
+    pub include_target: bool,
+}
+
+impl Default for JsonFormat {
+    fn default() -> Self {
+        Self {
+            flatten_event: true,
+            include_span_list: true,
+            include_target: true,
+        }
+    }
+}
+
+impl LogFormat {
+    /// Builds a boxed layer rendering events in this format, writing through `writer`.
+    pub(crate) fn layer<S, W>(self, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+        W: for<'writer> fmt::MakeWriter<'writer> + 'static + Send + Sync,
+    {
+        match self {
+            LogFormat::Full => Box::new(fmt::layer().with_writer(writer)),
+            LogFormat::Compact => Box::new(fmt::layer().compact().with_writer(writer)),
+            LogFormat::Pretty => Box::new(fmt::layer().pretty().with_writer(writer)),
+            LogFormat::Json(opts) => Box::new(
+                fmt::layer()
+                    .json()
+                    .flatten_event(opts.flatten_event)
+                    .with_span_list(opts.include_span_list)
+                    .with_target(opts.include_target)
+                    .with_writer(writer),
+            ),
+            LogFormat::Logfmt => Box::new(
+                fmt::layer()
+                    .event_format(LogfmtFormatter)
+                    .with_writer(writer),
+            ),
+        }
+    }
+}
+
+/// A [`FormatEvent`] implementation emitting `key=value` pairs, space-separated,
+/// quoting any value that contains whitespace.
+struct LogfmtFormatter;
+
+impl<S, N> FormatEvent<S, N> for LogfmtFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &tracing::Event<'_>,
+    ) -> std_fmt::Result {
+        let meta = event.metadata();
+        write!(writer, "level={} target={}", meta.level(), meta.target())?;
+
+        if let Some(scope) = ctx.event_scope() {
+            for span in scope.from_root() {
+                write!(writer, " span={}", span.name())?;
+            }
+        }
+
+        let mut visitor = LogfmtVisitor {
+            writer: &mut writer,
+            result: Ok(()),
+        };
+        event.record(&mut visitor);
+        visitor.result?;
+
+        writeln!(writer)
+    }
+}
+
+struct LogfmtVisitor<'a, 'writer> {
+    writer: &'a mut Writer<'writer>,
+    result: std_fmt::Result,
+}
+
+impl tracing::field::Visit for LogfmtVisitor<'_, '_> {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.write_pair(field, value);
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std_fmt::Debug) {
+        self.write_pair(field, &format!("{value:?}"));
+    }
+}
+
+impl LogfmtVisitor<'_, '_> {
+    fn write_pair(&mut self, field: &tracing::field::Field, value: &str) {
+        if self.result.is_err() {
+            return;
+        }
+        self.result = if needs_quoting(value) {
+            write!(self.writer, " {}=\"{}\"", field.name(), escape(value))
+        } else {
+            write!(self.writer, " {}={}", field.name(), value)
+        };
+    }
+}
+
+fn needs_quoting(value: &str) -> bool {
+    value.is_empty() || value.chars().any(|c| c.is_whitespace() || c == '=' || c == '"')
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
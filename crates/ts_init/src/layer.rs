@@ -10,3 +10,104 @@ pub fn env_filter_with_default<S: AsRef<str>>(
     tracing_subscriber::filter::EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| tracing_subscriber::filter::EnvFilter::new(default_env))
 }
+
+/// Default set of `(target, level)` suppression directives for common noisy
+/// transitive dependencies, used by [`env_filter_with_default_and_quiet`].
+pub const DEFAULT_QUIET_TARGETS: &[(&str, &str)] = &[
+    ("hyper", "warn"),
+    ("h2", "warn"),
+    ("tower", "warn"),
+    ("reqwest", "warn"),
+    ("trust_dns_proto", "warn"),
+    ("trust_dns_resolver", "warn"),
+    ("hickory_proto", "warn"),
+    ("hickory_resolver", "warn"),
+    ("jsonrpsee", "warn"),
+];
+
+/// Like [`env_filter_with_default`], but prepends `quiet` suppression directives
+/// before the default/user directive is applied.
+///
+/// `EnvFilter` lets a later directive override an earlier one for the same
+/// target, so the quiet directives are written first: the user directive —
+/// `RUST_LOG` if set, otherwise `default_env` — still wins for any target it
+/// mentions explicitly. Unlike [`env_filter_with_default`], the quiet set is
+/// prepended onto whichever of the two is actually used, so it isn't silently
+/// dropped when `RUST_LOG` is set. Pass [`DEFAULT_QUIET_TARGETS`] for a sane
+/// default, or your own set to extend or replace it.
+pub fn env_filter_with_default_and_quiet<S: AsRef<str>>(
+    default_env: S,
+    quiet: &[(&str, &str)],
+) -> tracing_subscriber::filter::EnvFilter {
+    let quiet_directives = quiet
+        .iter()
+        .map(|(target, level)| format!("{target}={level}"))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let user_directive = std::env::var(tracing_subscriber::filter::EnvFilter::DEFAULT_ENV)
+        .unwrap_or_else(|_| default_env.as_ref().to_owned());
+
+    let directive = if quiet_directives.is_empty() {
+        user_directive
+    } else {
+        format!("{quiet_directives},{user_directive}")
+    };
+
+    tracing_subscriber::filter::EnvFilter::new(directive)
+}
+
+/// Builds a `fmt::Layer` backed by a non-blocking, rotating file appender.
+///
+/// This is the building block behind
+/// [`TiSubscriberExt::with_rolling_file`](crate::TiSubscriberExt::with_rolling_file);
+/// use it directly when you need the layer without attaching it to a subscriber
+/// right away. The returned `WorkerGuard` must be kept alive for as long as the
+/// layer is in use, since dropping it flushes buffered log lines to disk.
+pub fn rolling_file<S>(
+    directory: impl AsRef<std::path::Path>,
+    file_name_prefix: impl Into<String>,
+    rotation: crate::rolling::Builder,
+) -> std::io::Result<(
+    tracing_subscriber::fmt::Layer<
+        S,
+        tracing_subscriber::fmt::format::DefaultFields,
+        tracing_subscriber::fmt::format::Format,
+        tracing_appender::non_blocking::NonBlocking,
+    >,
+    tracing_appender::non_blocking::WorkerGuard,
+)>
+where
+    S: tracing_core::Subscriber,
+    for<'span> S: tracing_subscriber::registry::LookupSpan<'span>,
+{
+    let appender = rotation.build(directory, file_name_prefix)?;
+    let (non_blocking, guard) = tracing_appender::non_blocking(appender);
+    Ok((
+        tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(non_blocking),
+        guard,
+    ))
+}
+
+/// Builds a `fmt::Layer` that ships one syslog message per event through `writer`.
+///
+/// This is the building block behind
+/// [`TiSubscriberExt::with_syslog`](crate::TiSubscriberExt::with_syslog).
+pub fn syslog<S>(
+    writer: crate::syslog::SyslogWriter,
+) -> tracing_subscriber::fmt::Layer<
+    S,
+    tracing_subscriber::fmt::format::DefaultFields,
+    tracing_subscriber::fmt::format::Format,
+    crate::syslog::SyslogMakeWriter,
+>
+where
+    S: tracing_core::Subscriber,
+    for<'span> S: tracing_subscriber::registry::LookupSpan<'span>,
+{
+    tracing_subscriber::fmt::layer()
+        .with_ansi(false)
+        .with_writer(crate::syslog::SyslogMakeWriter::new(writer))
+}
@@ -16,9 +16,14 @@
 //! ts_init::init(ts_init::env_filter_directive!("info"));
 //! ```
 
+pub mod format;
 pub mod layer;
 pub mod prelude;
+pub mod reload;
+pub mod rolling;
+pub mod syslog;
 
+pub use format::{JsonFormat, LogFormat};
 pub use tracing;
 pub use tracing_subscriber;
 
@@ -26,6 +31,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use tracing_subscriber::layer::Layered;
 use tracing_subscriber::layer::SubscriberExt as _;
+use tracing_subscriber::Layer;
 use tracing_subscriber::{
     fmt::{
         self,
@@ -125,6 +131,31 @@ pub fn subscriber() -> tracing_subscriber::fmt::Subscriber<
     builder().finish()
 }
 
+/// Creates a default subscriber that outputs logs to `stderr` in the given [`LogFormat`].
+///
+/// This is the runtime-selectable counterpart to [`builder`]/[`subscriber`], which are
+/// hard-wired to [`LogFormat::Full`].
+///
+/// # Example
+///
+/// ```
+/// use ts_init::prelude::*;
+/// use ts_init::LogFormat;
+///
+/// ts_init::builder_with_format(LogFormat::Json(Default::default())).init();
+/// ```
+pub fn builder_with_format(
+    format: LogFormat,
+) -> impl tracing_core::Subscriber + for<'span> LookupSpan<'span> {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    tracing_subscriber::Registry::default().with(
+        format
+            .layer(std::io::stderr)
+            .with_filter(tracing_subscriber::filter::LevelFilter::INFO),
+    )
+}
+
 pub fn try_init<S: AsRef<str>>(
     default_env: S,
 ) -> Result<(), tracing_subscriber::util::TryInitError> {
@@ -140,6 +171,26 @@ pub fn init<S: AsRef<str>>(default_env: S) {
     try_init(default_env).expect("Failed to initialize logging")
 }
 
+/// Initializes global logging across one or more [`LogDestination`]s.
+///
+/// This is the type-checked counterpart to the deprecated [`init_logging`], which
+/// took a `Vec<Option<String>>` of ad-hoc output strings.
+pub fn try_init_with_destinations<S: AsRef<str>>(
+    destinations: &[LogDestination],
+    default_env: S,
+) -> Result<(), tracing_subscriber::util::TryInitError> {
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    tracing_subscriber::Registry::default()
+        .with_destinations(destinations)
+        .with_env_filter_or(default_env.as_ref())
+        .try_init()
+}
+
+pub fn init_with_destinations<S: AsRef<str>>(destinations: &[LogDestination], default_env: S) {
+    try_init_with_destinations(destinations, default_env).expect("Failed to initialize logging")
+}
+
 #[derive(Clone)]
 pub struct FileMakeWriter {
     path: PathBuf,
@@ -165,6 +216,86 @@ impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for FileMakeWriter {
     }
 }
 
+/// A log output destination, parseable from the kind of strings a CLI flag would carry.
+///
+/// # Example
+///
+/// ```
+/// use ts_init::LogDestination;
+///
+/// assert_eq!("stderr".parse(), Ok(LogDestination::Stderr));
+/// assert_eq!("-".parse(), Ok(LogDestination::Stdout));
+/// assert_eq!("journald".parse(), Ok(LogDestination::Journald));
+/// assert_eq!(
+///     "/var/log/app.log".parse(),
+///     Ok(LogDestination::File("/var/log/app.log".into()))
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LogDestination {
+    /// Write to `stdout`. Parsed from `"-"` or `"stdout"`.
+    Stdout,
+    /// Write to `stderr`. Parsed from `"stderr"`.
+    Stderr,
+    /// Write to the systemd journal. Parsed from `"journald"`.
+    Journald,
+    /// Append to a file at the given path. Parsed from anything else.
+    File(PathBuf),
+}
+
+impl std::str::FromStr for LogDestination {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" | "stdout" => LogDestination::Stdout,
+            "stderr" => LogDestination::Stderr,
+            "journald" => LogDestination::Journald,
+            path => LogDestination::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl std::fmt::Display for LogDestination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogDestination::Stdout => f.write_str("stdout"),
+            LogDestination::Stderr => f.write_str("stderr"),
+            LogDestination::Journald => f.write_str("journald"),
+            LogDestination::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Builds one boxed layer per destination, in order.
+///
+/// This is the building block behind
+/// [`TiSubscriberExt::with_destinations`], exposed for callers who want to
+/// compose it into a subscriber themselves.
+pub fn layers_for_destinations<S>(
+    destinations: &[LogDestination],
+) -> Vec<Box<dyn Layer<S> + Send + Sync>>
+where
+    S: tracing_core::Subscriber,
+    for<'span> S: LookupSpan<'span>,
+{
+    destinations
+        .iter()
+        .map(|destination| match destination {
+            LogDestination::Stdout => {
+                Box::new(fmt::layer().with_writer(std::io::stdout)) as Box<dyn Layer<S> + Send + Sync>
+            }
+            LogDestination::Stderr => Box::new(fmt::layer().with_writer(std::io::stderr)),
+            LogDestination::Journald => Box::new(tracing_journald::layer().unwrap()),
+            LogDestination::File(path) => Box::new(
+                fmt::layer()
+                    .with_ansi(false)
+                    .with_writer(FileMakeWriter::new(path)),
+            ),
+        })
+        .collect()
+}
+
 pub trait TiSubscriberExt: tracing_core::subscriber::Subscriber {
     /// Adds an `EnvFilter` layer to this subscriber.
     ///
@@ -194,6 +325,32 @@ pub trait TiSubscriberExt: tracing_core::subscriber::Subscriber {
         self.with(layer::env_filter_with_default(default_env))
     }
 
+    /// Like [`with_env_filter_or`](Self::with_env_filter_or), but also silences
+    /// [`layer::DEFAULT_QUIET_TARGETS`] (common noisy networking dependencies)
+    /// unless `default_env` or `RUST_LOG` overrides them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ts_init::prelude::*;
+    ///
+    /// let subscriber = ts_init::subscriber()
+    ///     .with_env_filter_or_quiet("info,my_crate=debug")
+    ///     .init();
+    /// ```
+    fn with_env_filter_or_quiet<S: AsRef<str>>(
+        self,
+        default_env: S,
+    ) -> Layered<tracing_subscriber::EnvFilter, Self>
+    where
+        Self: Sized,
+    {
+        self.with(layer::env_filter_with_default_and_quiet(
+            default_env,
+            layer::DEFAULT_QUIET_TARGETS,
+        ))
+    }
+
     /// Adds a `fmt::Layer` to the `Subscriber` that writes logs to a specified file path.
     ///
     /// # Arguments
@@ -245,6 +402,152 @@ pub trait TiSubscriberExt: tracing_core::subscriber::Subscriber {
     {
         self.with(tracing_journald::layer().unwrap())
     }
+
+    /// Adds a layer that writes logs to a specified file path in the given [`LogFormat`].
+    ///
+    /// This is the format-selectable counterpart to [`with_file`](Self::with_file), which is
+    /// hard-wired to [`LogFormat::Full`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ts_init::prelude::*;
+    /// use ts_init::LogFormat;
+    ///
+    /// ts_init::subscriber()
+    ///     .with_file_format("app.log", LogFormat::Json(Default::default()))
+    ///     .init();
+    /// ```
+    fn with_file_format<P>(self, path: P, format: LogFormat) -> Layered<Box<dyn Layer<Self> + Send + Sync>, Self>
+    where
+        Self: Sized,
+        for<'span> Self: LookupSpan<'span>,
+        P: AsRef<Path>,
+    {
+        let path_buf = path.as_ref().to_owned();
+        self.with(format.layer(FileMakeWriter::new(path_buf)))
+    }
+
+    /// Adds a `stderr` layer rendering logs in the given [`LogFormat`].
+    ///
+    /// Useful when composing a subscriber from [`tracing_subscriber::Registry`] directly
+    /// instead of going through [`builder_with_format`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ts_init::prelude::*;
+    /// use ts_init::LogFormat;
+    ///
+    /// ts_init::tracing_subscriber::Registry::default()
+    ///     .with_format(LogFormat::Logfmt)
+    ///     .init();
+    /// ```
+    fn with_format(self, format: LogFormat) -> Layered<Box<dyn Layer<Self> + Send + Sync>, Self>
+    where
+        Self: Sized,
+        for<'span> Self: LookupSpan<'span>,
+    {
+        self.with(format.layer(std::io::stderr))
+    }
+
+    /// Composes one layer per [`LogDestination`] and adds them all to this subscriber.
+    ///
+    /// This replaces hand-rolled `match`ing over output strings: parse each
+    /// destination with `LogDestination::from_str` (infallible — anything that
+    /// isn't `stdout`/`stderr`/`journald` is treated as a file path) and pass
+    /// the resulting slice here.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ts_init::prelude::*;
+    /// use ts_init::LogDestination;
+    ///
+    /// let destinations: Vec<LogDestination> =
+    ///     ["stderr", "/var/log/app.log"].iter().map(|s| s.parse().unwrap()).collect();
+    ///
+    /// ts_init::tracing_subscriber::Registry::default()
+    ///     .with_destinations(&destinations)
+    ///     .init();
+    /// ```
+    fn with_destinations(
+        self,
+        destinations: &[LogDestination],
+    ) -> Layered<Vec<Box<dyn Layer<Self> + Send + Sync>>, Self>
+    where
+        Self: Sized,
+        for<'span> Self: LookupSpan<'span>,
+    {
+        self.with(layers_for_destinations(destinations))
+    }
+
+    /// Adds a rotating, non-blocking file layer to this subscriber.
+    ///
+    /// Internally this opens a [`rolling::RollingFileAppender`] (rotating by
+    /// size and/or calendar day, per `rotation`) and wraps it in a
+    /// `tracing-appender` non-blocking writer, so log writes never block the
+    /// calling thread.
+    ///
+    /// # Important
+    ///
+    /// The returned `WorkerGuard` must be kept alive for the lifetime of the
+    /// program. Dropping the guard flushes any lines still buffered in memory
+    /// and stops the background writer, so letting it go out of scope early —
+    /// or never binding it — silently loses the lines logged after that point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ts_init::prelude::*;
+    /// use ts_init::rolling::RollingFileAppender;
+    ///
+    /// let (subscriber, _guard) = ts_init::subscriber()
+    ///     .with_rolling_file("logs", "app.log", RollingFileAppender::builder())
+    ///     .expect("failed to open log file");
+    /// subscriber.init();
+    /// ```
+    fn with_rolling_file<P>(
+        self,
+        directory: P,
+        file_name_prefix: impl Into<String>,
+        rotation: rolling::Builder,
+    ) -> std::io::Result<(rolling::RollingFileLayered<Self>, tracing_appender::non_blocking::WorkerGuard)>
+    where
+        Self: Sized,
+        for<'span> Self: LookupSpan<'span>,
+        P: AsRef<Path>,
+    {
+        let (file_layer, guard) = layer::rolling_file(directory, file_name_prefix, rotation)?;
+        Ok((self.with(file_layer), guard))
+    }
+
+    /// Adds a layer that ships one syslog message per event through `writer`.
+    ///
+    /// Build `writer` with [`syslog::SyslogWriter::unix`] for the platform-local
+    /// socket, or [`syslog::SyslogWriter::udp`]/[`syslog::SyslogWriter::tcp`] for a
+    /// remote syslog server. ANSI output is always disabled, since syslog messages
+    /// are plain text.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ts_init::prelude::*;
+    /// use ts_init::syslog::{Facility, SyslogWriter};
+    ///
+    /// let writer = SyslogWriter::unix(Facility::Daemon, program_name!()).unwrap();
+    /// ts_init::subscriber().with_syslog(writer).init();
+    /// ```
+    fn with_syslog(
+        self,
+        writer: syslog::SyslogWriter,
+    ) -> Layered<fmt::Layer<Self, DefaultFields, Format<Full>, syslog::SyslogMakeWriter>, Self>
+    where
+        Self: Sized,
+        for<'span> Self: LookupSpan<'span>,
+    {
+        self.with(layer::syslog(writer))
+    }
 }
 
 impl<S: tracing_core::subscriber::Subscriber> TiSubscriberExt for S {}
@@ -260,6 +563,17 @@ impl<S: tracing_core::subscriber::Subscriber> TiSubscriberExt for S {}
 /// ```
 pub use ts_init_macros::env_filter_directive;
 
+/// Generates the default syslog program tag from `CARGO_BIN_NAME`, falling back
+/// to `CARGO_PKG_NAME` for library-only crates.
+///
+/// # Example
+/// ```
+/// use ts_init::prelude::*;
+/// let tag = program_name!();
+/// assert_eq!(tag, "ts_init");
+/// ```
+pub use ts_init_macros::program_name;
+
 #[deprecated(
     since = "0.2.0",
     note = "The `crate_env!` macro has been renamed to `env_filter_directive!`. Please update your usage."
@@ -0,0 +1,148 @@
+//! Runtime-reloadable filter and swappable log file destination.
+//!
+//! Long-running daemons often need to change verbosity or redirect their log
+//! file without restarting — e.g. in response to a SIGHUP or an admin API
+//! call. [`ReloadHandle`] pairs a [`tracing_subscriber::reload`] handle for the
+//! `EnvFilter` with a [`SwappableFileMakeWriter`] for the output file, so both
+//! can be changed atomically while logging is active.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use tracing_subscriber::filter::ParseError;
+use tracing_subscriber::reload;
+use tracing_subscriber::{EnvFilter, Registry};
+
+/// A [`tracing_subscriber::fmt::MakeWriter`] whose target file path can be
+/// swapped out at runtime.
+///
+/// This is an evolution of [`FileMakeWriter`](crate::FileMakeWriter): the path
+/// lives behind an `Arc<RwLock<_>>` so [`SwappableFileMakeWriter::set_path`]
+/// can redirect subsequent writes without tearing down the subscriber.
+#[derive(Clone)]
+pub struct SwappableFileMakeWriter {
+    path: Arc<RwLock<PathBuf>>,
+}
+
+impl SwappableFileMakeWriter {
+    /// Creates a writer that initially appends to `path`.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: Arc::new(RwLock::new(path.as_ref().to_owned())),
+        }
+    }
+
+    /// Redirects subsequent writes to `path`.
+    ///
+    /// Safe to call concurrently with active logging: it only changes which
+    /// path the *next* `make_writer` call opens, it does not affect a file
+    /// handle that's already open.
+    pub fn set_path<P: AsRef<Path>>(&self, path: P) {
+        *self.path.write().unwrap() = path.as_ref().to_owned();
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for SwappableFileMakeWriter {
+    type Writer = fs::File;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        let path = self.path.read().unwrap().clone();
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .expect("unable to open log file")
+    }
+}
+
+/// Error returned by [`ReloadHandle::set_filter`].
+#[derive(Debug)]
+pub enum ReloadError {
+    /// The given directive string failed to parse as an `EnvFilter`.
+    Parse(ParseError),
+    /// Swapping in the new filter failed: the subscriber it was attached to has
+    /// since been dropped, or the filter's lock was poisoned.
+    Reload(reload::Error),
+}
+
+impl std::fmt::Display for ReloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReloadError::Parse(e) => write!(f, "failed to parse filter directive: {e}"),
+            ReloadError::Reload(e) => write!(f, "failed to swap in new filter: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReloadError {}
+
+impl From<ParseError> for ReloadError {
+    fn from(e: ParseError) -> Self {
+        ReloadError::Parse(e)
+    }
+}
+
+impl From<reload::Error> for ReloadError {
+    fn from(e: reload::Error) -> Self {
+        ReloadError::Reload(e)
+    }
+}
+
+/// A handle for atomically swapping the active `EnvFilter` and log file path at runtime.
+///
+/// Returned by [`try_init_with_reload`]/[`init_with_reload`]. Cloning is cheap,
+/// and `set_filter`/`change_log_file` are safe to call concurrently with
+/// in-flight logging from any thread.
+#[derive(Clone)]
+pub struct ReloadHandle {
+    filter: reload::Handle<EnvFilter, Registry>,
+    file: SwappableFileMakeWriter,
+}
+
+impl ReloadHandle {
+    /// Reparses `directive` as an `EnvFilter` and swaps it in atomically.
+    pub fn set_filter(&self, directive: &str) -> Result<(), ReloadError> {
+        let filter = EnvFilter::try_new(directive)?;
+        self.filter.reload(filter)?;
+        Ok(())
+    }
+
+    /// Redirects subsequent log writes to `path`.
+    pub fn change_log_file<P: AsRef<Path>>(&self, path: P) {
+        self.file.set_path(path);
+    }
+}
+
+/// Initializes global logging with a reloadable filter and a swappable log file,
+/// returning a [`ReloadHandle`] for changing either at runtime.
+pub fn try_init_with_reload<S: AsRef<str>>(
+    path: impl AsRef<Path>,
+    default_env: S,
+) -> Result<ReloadHandle, tracing_subscriber::util::TryInitError> {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let (filter_layer, filter_handle) =
+        reload::Layer::new(crate::layer::env_filter_with_default(default_env.as_ref()));
+
+    let file = SwappableFileMakeWriter::new(path);
+    let file_layer = tracing_subscriber::fmt::layer()
+        .with_ansi(false)
+        .with_writer(file.clone());
+
+    Registry::default()
+        .with(filter_layer)
+        .with(file_layer)
+        .try_init()?;
+
+    Ok(ReloadHandle {
+        filter: filter_handle,
+        file,
+    })
+}
+
+/// Like [`try_init_with_reload`], but panics on failure.
+pub fn init_with_reload<S: AsRef<str>>(path: impl AsRef<Path>, default_env: S) -> ReloadHandle {
+    try_init_with_reload(path, default_env).expect("Failed to initialize logging")
+}
@@ -0,0 +1,199 @@
+//! A size- and day-based rotating file appender.
+//!
+//! Unlike `tracing_appender::rolling`, which rotates purely on a fixed time
+//! interval, [`RollingFileAppender`] rotates whenever the *current* file grows
+//! past a configured byte threshold, the calendar day changes, or both,
+//! whichever comes first. Historical files are kept up to a configurable
+//! count and are renamed with an incrementing index suffix (`app.log.1` is
+//! the most recent rotation, `app.log.2` the one before that, and so on).
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_MAX_FILES: usize = 7;
+
+/// Configures the rotation policy for a [`RollingFileAppender`].
+///
+/// # Example
+///
+/// ```
+/// use ts_init::rolling::RollingFileAppender;
+///
+/// let appender = RollingFileAppender::builder()
+///     .max_bytes(50 * 1024 * 1024)
+///     .max_files(14)
+///     .build("logs", "app.log")
+///     .expect("failed to open log file");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Builder {
+    max_bytes: u64,
+    max_files: usize,
+    rotate_on_day_change: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_BYTES,
+            max_files: DEFAULT_MAX_FILES,
+            rotate_on_day_change: true,
+        }
+    }
+}
+
+impl Builder {
+    /// Creates a builder with the default policy (10 MiB, 7 historical files,
+    /// rotate on UTC day change).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size, in bytes, a log file may reach before it is rotated.
+    pub fn max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Sets the maximum number of historical (rotated) files to retain.
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Enables or disables rotation when the calendar day (UTC) changes.
+    pub fn rotate_on_day_change(mut self, rotate: bool) -> Self {
+        self.rotate_on_day_change = rotate;
+        self
+    }
+
+    /// Opens (creating if necessary) `directory/file_name_prefix` and returns an
+    /// appender configured with this policy.
+    pub fn build(
+        self,
+        directory: impl AsRef<Path>,
+        file_name_prefix: impl Into<String>,
+    ) -> io::Result<RollingFileAppender> {
+        let directory = directory.as_ref().to_owned();
+        fs::create_dir_all(&directory)?;
+        let file_name_prefix = file_name_prefix.into();
+        let path = directory.join(&file_name_prefix);
+        let current_file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let current_bytes = current_file.metadata()?.len();
+
+        Ok(RollingFileAppender {
+            inner: Mutex::new(Inner {
+                directory,
+                file_name_prefix,
+                max_bytes: self.max_bytes,
+                max_files: self.max_files,
+                rotate_on_day_change: self.rotate_on_day_change,
+                current_file,
+                current_bytes,
+                current_day: today(),
+            }),
+        })
+    }
+}
+
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        / 86_400
+}
+
+struct Inner {
+    directory: PathBuf,
+    file_name_prefix: String,
+    max_bytes: u64,
+    max_files: usize,
+    rotate_on_day_change: bool,
+    current_file: File,
+    current_bytes: u64,
+    current_day: u64,
+}
+
+impl Inner {
+    fn should_rotate(&self) -> bool {
+        self.current_bytes >= self.max_bytes
+            || (self.rotate_on_day_change && today() != self.current_day)
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        self.directory
+            .join(format!("{}.{}", self.file_name_prefix, index))
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        let path = self.directory.join(&self.file_name_prefix);
+        if path.exists() {
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(&from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&path, self.rotated_path(1))?;
+            let _ = fs::remove_file(self.rotated_path(self.max_files + 1));
+        }
+
+        self.current_file = OpenOptions::new().create(true).append(true).open(&path)?;
+        self.current_bytes = 0;
+        self.current_day = today();
+        Ok(())
+    }
+}
+
+/// A [`std::io::Write`] implementation that rotates its underlying file by size
+/// and/or calendar day, keeping at most a configured number of historical files.
+///
+/// Construct one with [`RollingFileAppender::builder`], then hand it to
+/// [`tracing_appender::non_blocking`] so writes don't block the logging
+/// thread — or simply use
+/// [`TiSubscriberExt::with_rolling_file`](crate::TiSubscriberExt::with_rolling_file),
+/// which does this for you.
+pub struct RollingFileAppender {
+    inner: Mutex<Inner>,
+}
+
+impl RollingFileAppender {
+    /// Returns a [`Builder`] for configuring the rotation policy.
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+}
+
+/// The subscriber type produced by
+/// [`TiSubscriberExt::with_rolling_file`](crate::TiSubscriberExt::with_rolling_file):
+/// `subscriber` layered with a non-blocking, rotating file writer.
+pub type RollingFileLayered<S> = tracing_subscriber::layer::Layered<
+    tracing_subscriber::fmt::Layer<
+        S,
+        tracing_subscriber::fmt::format::DefaultFields,
+        tracing_subscriber::fmt::format::Format,
+        tracing_appender::non_blocking::NonBlocking,
+    >,
+    S,
+>;
+
+impl Write for RollingFileAppender {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.should_rotate() {
+            inner.rotate()?;
+        }
+        let written = inner.current_file.write(buf)?;
+        inner.current_bytes += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.lock().unwrap().current_file.flush()
+    }
+}
@@ -0,0 +1,209 @@
+//! A `tracing_subscriber` layer that ships events to a syslog daemon, local or remote.
+
+use std::io::{self, Write};
+use std::net::{TcpStream, ToSocketAddrs, UdpSocket};
+use std::os::unix::net::UnixDatagram;
+use std::sync::{Arc, Mutex};
+
+/// Syslog facility code (RFC 3164 §4.1.1).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Facility {
+    /// System daemons without a separate facility value.
+    Daemon,
+    /// Generic user-level messages.
+    User,
+    /// Locally used facility 0.
+    Local0,
+    /// Locally used facility 1.
+    Local1,
+    /// Locally used facility 2.
+    Local2,
+    /// Locally used facility 3.
+    Local3,
+    /// Locally used facility 4.
+    Local4,
+    /// Locally used facility 5.
+    Local5,
+    /// Locally used facility 6.
+    Local6,
+    /// Locally used facility 7.
+    Local7,
+}
+
+impl Facility {
+    fn code(self) -> u8 {
+        match self {
+            Facility::Daemon => 3,
+            Facility::User => 1,
+            Facility::Local0 => 16,
+            Facility::Local1 => 17,
+            Facility::Local2 => 18,
+            Facility::Local3 => 19,
+            Facility::Local4 => 20,
+            Facility::Local5 => 21,
+            Facility::Local6 => 22,
+            Facility::Local7 => 23,
+        }
+    }
+}
+
+/// Maps a `tracing::Level` to an RFC 3164 syslog severity code.
+fn severity_for_level(level: &tracing::Level) -> u8 {
+    match *level {
+        tracing::Level::ERROR => 3,
+        tracing::Level::WARN => 4,
+        tracing::Level::INFO => 6,
+        tracing::Level::DEBUG | tracing::Level::TRACE => 7,
+    }
+}
+
+enum Transport {
+    Unix(UnixDatagram),
+    Udp(UdpSocket, std::net::SocketAddr),
+    Tcp(Mutex<TcpStream>),
+}
+
+/// Sends formatted events to a syslog daemon over a Unix datagram socket, UDP, or TCP.
+///
+/// Construct one with [`SyslogWriter::unix`], [`SyslogWriter::udp`], or
+/// [`SyslogWriter::tcp`], then pass it to
+/// [`TiSubscriberExt::with_syslog`](crate::TiSubscriberExt::with_syslog).
+pub struct SyslogWriter {
+    transport: Transport,
+    facility: Facility,
+    tag: String,
+}
+
+impl SyslogWriter {
+    /// Connects to the platform-local syslog Unix datagram socket (`/dev/log` or
+    /// `/var/run/syslog`).
+    pub fn unix(facility: Facility, tag: impl Into<String>) -> io::Result<Self> {
+        let socket = UnixDatagram::unbound()?;
+        let path = ["/dev/log", "/var/run/syslog"]
+            .into_iter()
+            .find(|path| std::path::Path::new(path).exists())
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "no local syslog socket found")
+            })?;
+        socket.connect(path)?;
+        Ok(Self {
+            transport: Transport::Unix(socket),
+            facility,
+            tag: tag.into(),
+        })
+    }
+
+    /// Ships messages to a remote syslog server over UDP.
+    pub fn udp(
+        facility: Facility,
+        tag: impl Into<String>,
+        addr: impl ToSocketAddrs,
+    ) -> io::Result<Self> {
+        let addr = addr.to_socket_addrs()?.next().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "no address resolved")
+        })?;
+        let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+        Ok(Self {
+            transport: Transport::Udp(socket, addr),
+            facility,
+            tag: tag.into(),
+        })
+    }
+
+    /// Ships messages to a remote syslog server over TCP.
+    pub fn tcp(
+        facility: Facility,
+        tag: impl Into<String>,
+        addr: impl ToSocketAddrs,
+    ) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        Ok(Self {
+            transport: Transport::Tcp(Mutex::new(stream)),
+            facility,
+            tag: tag.into(),
+        })
+    }
+
+    fn send(&self, severity: u8, message: &str) -> io::Result<()> {
+        let priority = self.facility.code() * 8 + severity;
+        let packet = format!("<{priority}>{}: {message}\n", self.tag);
+
+        match &self.transport {
+            Transport::Unix(socket) => {
+                socket.send(packet.as_bytes())?;
+            }
+            Transport::Udp(socket, addr) => {
+                socket.send_to(packet.as_bytes(), addr)?;
+            }
+            Transport::Tcp(stream) => {
+                stream.lock().unwrap().write_all(packet.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A [`tracing_subscriber::fmt::MakeWriter`] that hands out one [`SyslogLineWriter`]
+/// per event, picking the syslog severity from the event's [`tracing::Level`].
+#[derive(Clone)]
+pub struct SyslogMakeWriter {
+    inner: Arc<SyslogWriter>,
+}
+
+impl SyslogMakeWriter {
+    /// Wraps `writer` so it can be used as a `fmt::Layer` writer.
+    pub fn new(writer: SyslogWriter) -> Self {
+        Self {
+            inner: Arc::new(writer),
+        }
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for SyslogMakeWriter {
+    type Writer = SyslogLineWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        SyslogLineWriter {
+            writer: self.inner.clone(),
+            severity: severity_for_level(&tracing::Level::INFO),
+            buf: Vec::new(),
+        }
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer {
+        SyslogLineWriter {
+            writer: self.inner.clone(),
+            severity: severity_for_level(meta.level()),
+            buf: Vec::new(),
+        }
+    }
+}
+
+/// Buffers one event's formatted output and sends it as a single syslog message on flush.
+pub struct SyslogLineWriter {
+    writer: Arc<SyslogWriter>,
+    severity: u8,
+    buf: Vec<u8>,
+}
+
+impl Write for SyslogLineWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.buf.is_empty() {
+            let message = String::from_utf8_lossy(&self.buf);
+            self.writer.send(self.severity, message.trim_end())?;
+            self.buf.clear();
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SyslogLineWriter {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
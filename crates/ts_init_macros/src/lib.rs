@@ -17,3 +17,15 @@ pub fn env_filter_directive(input: TokenStream) -> TokenStream {
     let lit = LitStr::new(&directive, proc_macro2::Span::call_site());
     TokenStream::from(quote!( #lit ))
 }
+
+/// Expands to the value of `CARGO_BIN_NAME` at the call site, falling back to
+/// `CARGO_PKG_NAME` for crates that don't define a binary target. Used as the
+/// default syslog program tag.
+#[proc_macro]
+pub fn program_name(_input: TokenStream) -> TokenStream {
+    let pkg = std::env::var("CARGO_PKG_NAME").expect("CARGO_PKG_NAME must be set by Cargo");
+    let name = std::env::var("CARGO_BIN_NAME").unwrap_or(pkg);
+
+    let lit = LitStr::new(&name, proc_macro2::Span::call_site());
+    TokenStream::from(quote!( #lit ))
+}